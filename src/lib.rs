@@ -3,9 +3,12 @@
 #![no_std]
 
 use core::borrow::{Borrow, BorrowMut};
-use core::ops::{Deref, DerefMut};
-use core::mem::replace;
-use core::cmp;
+use core::ops::{Bound, Deref, DerefMut, RangeBounds};
+use core::mem::{replace, MaybeUninit};
+use core::cmp::{self, Ordering};
+use core::hash::{Hash, Hasher};
+use core::fmt;
+use core::ptr;
 
 /// A Vector using a slice for backing storage (passed in at creation time).
 ///
@@ -67,6 +70,65 @@ impl<'a, T> SliceVec<'a, T> {
         }
     }
 
+    /// Pushes elements from an iterator until it is exhausted or the backing storage is full.
+    ///
+    /// Returns `Ok(())` if the whole iterator was consumed, or `Err(elem)` with the first element
+    /// that did not fit. Elements pushed before the storage filled up are kept.
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), T> {
+        for elem in iter {
+            self.push(elem)?;
+        }
+        Ok(())
+    }
+
+    /// Splits the vector in two at `at`, returning a new `SliceVec` that borrows the tail of the
+    /// backing storage.
+    ///
+    /// After the call, `self` contains the elements `[0, at)` and has its capacity reduced to `at`;
+    /// the returned `SliceVec` contains the elements `[at, len)` and borrows the remainder of the
+    /// original backing slice, so no elements are copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> SliceVec<'a, T> {
+        assert!(at <= self.len, "split_off index out of bounds");
+        let back_len = self.len - at;
+        // Reborrow the backing slice in two by temporarily swapping in an empty slice.
+        let storage = replace(&mut self.storage, &mut []);
+        let (front, back) = storage.split_at_mut(at);
+        self.storage = front;
+        self.len = at;
+        SliceVec {
+            storage: back,
+            len: back_len,
+        }
+    }
+
+    /// Inserts an element at `index`, shifting all elements after it to the right.
+    ///
+    /// If the backing storage is already full, returns `Err(elem)` without modifying the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, elem: T) -> Result<(), T> {
+        assert!(index <= self.len, "insertion index out of bounds");
+        if self.len < self.capacity() {
+            // Carry the displaced element along as we shift the tail one slot to the right. The
+            // value that falls out of the first unused slot (index `len`) is a "dummy" and gets
+            // dropped.
+            let mut carry = elem;
+            for i in index..=self.len {
+                carry = replace(&mut self.storage[i], carry);
+            }
+            self.len += 1;
+            Ok(())
+        } else {
+            Err(elem)
+        }
+    }
+
     /// Removes and returns the last elements stored inside the vector, replacing it with `elem`.
     ///
     /// If the vector is empty, returns `None` and drops `elem`.
@@ -122,6 +184,90 @@ impl<'a, T: 'a + Default> SliceVec<'a, T> {
         }
     }
 
+    /// Removes the given range of elements and returns an iterator over them.
+    ///
+    /// The elements are yielded in order. When the returned `Drain` is dropped, any elements in the
+    /// range that have not been consumed are dropped, and the tail following the range is shifted
+    /// left to close the gap. Like `pop`, this requires `T: Default`, since the vacated slots at the
+    /// end of the backing storage are refilled with default values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the end is greater than
+    /// `len`.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, 'a, T> {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end, "drain start is greater than end");
+        assert!(end <= self.len, "drain end is out of bounds");
+
+        let orig_len = self.len;
+        // Logically detach the tail: the elements left in the vector for now are the `start` prefix.
+        // `Drain::drop` restores the tail after the removed range.
+        self.len = start;
+        Drain {
+            vec: self,
+            start: start,
+            idx: start,
+            end: end,
+            orig_len: orig_len,
+        }
+    }
+
+    /// Retains only the elements for which the predicate returns `true`.
+    ///
+    /// The elements are visited in order, and the retained ones are compacted towards the front of
+    /// the backing storage. Like `pop`, this requires `T: Default`, since the slots vacated during
+    /// compaction are refilled with default values.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Retains only the elements for which the predicate returns `true`, passing a mutable
+    /// reference so the predicate may also modify the elements it keeps.
+    ///
+    /// Behaves like [`retain`](Self::retain) otherwise.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let len = self.len;
+        let mut w = 0;
+        for r in 0..len {
+            if f(&mut self.storage[r]) {
+                if w != r {
+                    let elem = replace(&mut self.storage[r], T::default());
+                    self.storage[w] = elem;
+                }
+                w += 1;
+            }
+        }
+        self.len = w;
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left.
+    ///
+    /// This operation is restricted to element types that implement `Default`, since the vacated
+    /// last slot in the backing storage is filled with a default value.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index out of bounds");
+        // Shift the tail left by carrying the default filler from the last slot backwards; the
+        // carried value emerging from `index` is the removed element.
+        let mut carry = T::default();
+        for i in (index..self.len).rev() {
+            carry = replace(&mut self.storage[i], carry);
+        }
+        self.len -= 1;
+        carry
+    }
+
     /// Removes and returns the element at `index` and replaces it with the last element.
     ///
     /// Panics if `index` is out of bounds.
@@ -133,6 +279,75 @@ impl<'a, T: 'a + Default> SliceVec<'a, T> {
     }
 }
 
+impl<'a, T: Clone> SliceVec<'a, T> {
+    /// Clones and appends all elements in `other` that fit into the remaining capacity.
+    ///
+    /// Returns `Ok(())` if every element was appended, or `Err(n)` where `n` is the number of
+    /// elements that were written before the backing storage filled up.
+    pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), usize> {
+        let take = cmp::min(other.len(), self.capacity() - self.len);
+        for i in 0..take {
+            self.storage[self.len + i] = other[i].clone();
+        }
+        self.len += take;
+        if take == other.len() {
+            Ok(())
+        } else {
+            Err(take)
+        }
+    }
+}
+
+/// A draining iterator over a range of elements in a `SliceVec`.
+///
+/// This is created by [`SliceVec::drain`]. See its documentation for details.
+pub struct Drain<'d, 'a: 'd, T: 'a + Default> {
+    vec: &'d mut SliceVec<'a, T>,
+    /// Index the tail is shifted back to when the `Drain` is dropped.
+    start: usize,
+    /// Index of the next element to yield.
+    idx: usize,
+    /// One past the last index in the drained range.
+    end: usize,
+    /// Length of the vector before draining began.
+    orig_len: usize,
+}
+
+impl<'d, 'a, T: Default> Iterator for Drain<'d, 'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx < self.end {
+            let elem = replace(&mut self.vec.storage[self.idx], T::default());
+            self.idx += 1;
+            Some(elem)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'d, 'a, T: Default> Drop for Drain<'d, 'a, T> {
+    fn drop(&mut self) {
+        // Drop any elements the consumer didn't take.
+        while let Some(_) = self.next() {}
+
+        // Shift the tail following the drained range left to close the gap, refilling the slots it
+        // leaves behind with defaults.
+        let tail = self.orig_len - self.end;
+        for i in 0..tail {
+            let elem = replace(&mut self.vec.storage[self.end + i], T::default());
+            self.vec.storage[self.start + i] = elem;
+        }
+        self.vec.len = self.start + tail;
+    }
+}
+
 impl<'a, T> Deref for SliceVec<'a, T> {
     type Target = [T];
 
@@ -174,6 +389,207 @@ impl<'a, T> BorrowMut<[T]> for SliceVec<'a, T> {
     }
 }
 
+/// A vector that uses a slice of `MaybeUninit<T>` for backing storage.
+///
+/// Unlike [`SliceVec`], the backing storage does not need to hold valid `T` values: only the first
+/// `len` slots are guaranteed to be initialized. This means `push` does not drop any previous
+/// contents, `pop` does not need to leave a valid value behind, and neither the type nor any of its
+/// operations require `T: Default`. It is therefore suited to owning genuinely uninitialized scratch
+/// buffers, such as a stack array of `MaybeUninit<T>`.
+///
+/// Dropping the `UninitSliceVec` drops the initialized prefix in place, so the elements do not
+/// remain as valid values in the backing storage afterwards; the remaining slots are left
+/// uninitialized.
+pub struct UninitSliceVec<'a, T: 'a> {
+    /// Backing storage. The first `len` slots are always initialized; the rest are uninitialized.
+    storage: &'a mut [MaybeUninit<T>],
+    len: usize,
+}
+
+impl<'a, T> UninitSliceVec<'a, T> {
+    /// Create a new `UninitSliceVec`, using the given slice of uninitialized memory as backing
+    /// storage for elements.
+    ///
+    /// The capacity of the vector equals the length of the slice.
+    pub fn new(storage: &'a mut [MaybeUninit<T>]) -> Self {
+        UninitSliceVec {
+            storage: storage,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of elements that can be stored in this vector.
+    pub fn capacity(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns the number of elements stored in this `UninitSliceVec`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the length of this vector is 0, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Tries to append an element to the end of this vector.
+    ///
+    /// If the backing storage is already full, returns `Err(elem)`.
+    pub fn push(&mut self, elem: T) -> Result<(), T> {
+        if self.len < self.capacity() {
+            // The slot at `self.len` is uninitialized, so `write` does not drop anything.
+            self.storage[self.len].write(elem);
+            self.len += 1;
+            Ok(())
+        } else {
+            Err(elem)
+        }
+    }
+
+    /// Removes and returns the last element in this vector.
+    ///
+    /// Returns `None` if the vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len > 0 {
+            self.len -= 1;
+            // Safety: the slot was within the initialized prefix; shrinking `len` marks it
+            // uninitialized again, so the value is not read or dropped a second time.
+            Some(unsafe { self.storage[self.len].assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    /// Removes and returns the element at `index` and replaces it with the last element.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "swap_remove index out of bounds");
+        self.storage.swap(index, self.len - 1);
+        self.pop().expect("swap_remove failed pop")
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index out of bounds");
+        // Safety: `index` is within the initialized prefix. The read-out slot is bubbled to the end
+        // by the swaps below and falls outside the prefix once `len` shrinks, so it is never read
+        // again.
+        let elem = unsafe { self.storage[index].assume_init_read() };
+        for i in index..self.len - 1 {
+            self.storage.swap(i, i + 1);
+        }
+        self.len -= 1;
+        elem
+    }
+
+    /// Extract a slice containing the entire vector.
+    pub fn as_slice(&self) -> &[T] {
+        let init = &self.storage[..self.len];
+        // Safety: the first `len` slots are guaranteed to be initialized, and `MaybeUninit<T>` has
+        // the same layout as `T`.
+        unsafe { &*(init as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    /// Extract a mutable slice containing the entire vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let init = &mut self.storage[..self.len];
+        // Safety: see `as_slice`.
+        unsafe { &mut *(init as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
+
+impl<'a, T> Drop for UninitSliceVec<'a, T> {
+    fn drop(&mut self) {
+        // Safety: exactly the initialized prefix is dropped, in place, once.
+        unsafe {
+            ptr::drop_in_place(self.as_mut_slice());
+        }
+    }
+}
+
+impl<'a, T> Deref for UninitSliceVec<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T> DerefMut for UninitSliceVec<'a, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for UninitSliceVec<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+// Comparison, equality and hashing, all delegating to the contained slice so that a `SliceVec`
+// behaves like `Vec<T>` in generic, slice-like contexts.
+
+impl<'a, 'b, A, B> PartialEq<SliceVec<'b, B>> for SliceVec<'a, A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &SliceVec<'b, B>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'a, A, B> PartialEq<[B]> for SliceVec<'a, A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a, 'b, A, B> PartialEq<&'b [B]> for SliceVec<'a, A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &&'b [B]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<'a, A, B, const N: usize> PartialEq<[B; N]> for SliceVec<'a, A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &[B; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a, T: Eq> Eq for SliceVec<'a, T> {}
+
+impl<'a, T: PartialOrd> PartialOrd for SliceVec<'a, T> {
+    fn partial_cmp(&self, other: &SliceVec<'a, T>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<'a, T: Ord> Ord for SliceVec<'a, T> {
+    fn cmp(&self, other: &SliceVec<'a, T>) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<'a, T: Hash> Hash for SliceVec<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
 #[test]
 fn basic() {
     const CAP: usize = 1;
@@ -224,3 +640,169 @@ fn swap_remove() {
         assert_eq!(s.as_slice().len(), 2);
     }
 }
+
+#[test]
+fn insert_remove() {
+    let mut storage = [0; 4];
+
+    {
+        let mut s = SliceVec::new(&mut storage);
+        assert_eq!(s.insert(0, 1), Ok(()));
+        assert_eq!(s.insert(0, 0), Ok(()));
+        assert_eq!(s.insert(2, 3), Ok(()));
+        assert_eq!(s.insert(2, 2), Ok(()));
+        assert_eq!(s.as_slice(), &[0, 1, 2, 3]);
+        assert_eq!(s.insert(0, 9), Err(9));
+
+        assert_eq!(s.remove(1), 1);
+        assert_eq!(s.as_slice(), &[0, 2, 3]);
+        assert_eq!(s.remove(2), 3);
+        assert_eq!(s.as_slice(), &[0, 2]);
+        assert_eq!(s.remove(0), 0);
+        assert_eq!(s.as_slice(), &[2]);
+    }
+}
+
+#[test]
+fn drain() {
+    let mut storage = [0; 5];
+
+    {
+        let mut s = SliceVec::new(&mut storage);
+        for i in 0..5 {
+            assert_eq!(s.push(i), Ok(()));
+        }
+
+        let drained: [i32; 2] = {
+            let mut it = s.drain(1..3);
+            [it.next().unwrap(), it.next().unwrap()]
+        };
+        assert_eq!(&drained, &[1, 2]);
+        assert_eq!(s.as_slice(), &[0, 3, 4]);
+
+        // Dropping without consuming still closes the gap.
+        s.drain(..1);
+        assert_eq!(s.as_slice(), &[3, 4]);
+
+        s.drain(..);
+        assert!(s.is_empty());
+    }
+}
+
+#[test]
+fn retain() {
+    let mut storage = [0; 6];
+
+    {
+        let mut s = SliceVec::new(&mut storage);
+        for i in 0..6 {
+            assert_eq!(s.push(i), Ok(()));
+        }
+
+        s.retain(|&x| x % 2 == 0);
+        assert_eq!(s.as_slice(), &[0, 2, 4]);
+
+        s.retain_mut(|x| {
+            *x += 1;
+            *x != 3
+        });
+        assert_eq!(s.as_slice(), &[1, 5]);
+    }
+}
+
+#[test]
+fn compare() {
+    let mut sa = [0; 3];
+    let mut sb = [0; 4];
+
+    {
+        let mut a = SliceVec::new(&mut sa);
+        let mut b = SliceVec::new(&mut sb);
+        for i in 0..3 {
+            assert_eq!(a.push(i), Ok(()));
+            assert_eq!(b.push(i), Ok(()));
+        }
+
+        assert_eq!(a, b);
+        assert!(a == [0, 1, 2]);
+        assert!(a == [0, 1, 2][..]);
+
+        assert_eq!(b.push(3), Ok(()));
+        assert!(a < b);
+        assert!(a != b);
+    }
+}
+
+#[test]
+fn uninit() {
+    let mut storage: [MaybeUninit<i32>; 3] = [
+        MaybeUninit::uninit(),
+        MaybeUninit::uninit(),
+        MaybeUninit::uninit(),
+    ];
+
+    {
+        let mut s = UninitSliceVec::new(&mut storage);
+        assert!(s.is_empty());
+        assert_eq!(s.capacity(), 3);
+
+        assert_eq!(s.push(0), Ok(()));
+        assert_eq!(s.push(1), Ok(()));
+        assert_eq!(s.push(2), Ok(()));
+        assert_eq!(s.push(3), Err(3));
+        assert_eq!(s.as_slice(), &[0, 1, 2]);
+
+        assert_eq!(s.remove(0), 0);
+        assert_eq!(s.as_slice(), &[1, 2]);
+        assert_eq!(s.swap_remove(0), 1);
+        assert_eq!(s.as_slice(), &[2]);
+        assert_eq!(s.pop(), Some(2));
+        assert_eq!(s.pop(), None);
+    }
+}
+
+#[test]
+fn bulk_extend() {
+    let mut storage = [0; 4];
+
+    {
+        let mut s = SliceVec::new(&mut storage);
+        assert_eq!(s.extend_from_slice(&[0, 1]), Ok(()));
+        assert_eq!(s.as_slice(), &[0, 1]);
+        assert_eq!(s.extend_from_slice(&[2, 3, 4]), Err(2));
+        assert_eq!(s.as_slice(), &[0, 1, 2, 3]);
+    }
+
+    let mut storage = [0; 3];
+    {
+        let mut s = SliceVec::new(&mut storage);
+        assert_eq!(s.try_extend(0..2), Ok(()));
+        assert_eq!(s.as_slice(), &[0, 1]);
+        assert_eq!(s.try_extend(2..5), Err(3));
+        assert_eq!(s.as_slice(), &[0, 1, 2]);
+    }
+}
+
+#[test]
+fn split_off() {
+    let mut storage = [0; 5];
+
+    {
+        let mut s = SliceVec::new(&mut storage);
+        assert_eq!(s.try_extend(0..4), Ok(()));
+
+        let mut tail = s.split_off(2);
+        assert_eq!(s.as_slice(), &[0, 1]);
+        assert_eq!(s.capacity(), 2);
+        assert_eq!(tail.as_slice(), &[2, 3]);
+        assert_eq!(tail.capacity(), 3);
+
+        // The tail half keeps growing into the rest of the original backing slice.
+        assert_eq!(tail.push(9), Ok(()));
+        assert_eq!(tail.as_slice(), &[2, 3, 9]);
+        assert_eq!(tail.push(10), Err(10));
+
+        // The front half is independently full.
+        assert_eq!(s.push(8), Err(8));
+    }
+}